@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+
+use crate::{Mapping, with_scratch};
+
+// LookupRequest enum that describes a single named lookup for `Mapping::remap_many`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LookupRequest<'a> {
+    Class { class_name: &'a str },
+    Method { class_name: &'a str, method_name: &'a str, descriptor: &'a str },
+    Field { class_name: &'a str, field_name: &'a str, descriptor: &'a str },
+}
+
+// LookupResult holds the obfuscated name for a request, or None if it is not part of the mapping.
+pub type LookupResult = Option<String>;
+
+impl Mapping {
+
+    ///
+    /// Remaps a batch of named lookups to their obfuscated counterparts.
+    ///
+    /// Results are returned in the same order as the requests and match what
+    /// `remap_class`, `remap_method` and `remap_field` would return for each of them.
+    /// Remapped descriptors are cached for the duration of the batch and the lookup key of
+    /// the scratch buffers is reused between requests, so large batches avoid most per-call allocations.
+    ///
+    pub fn remap_many(&self, requests: &[LookupRequest]) -> Vec<LookupResult> {
+        let mut descriptors: HashMap<&str, String> = HashMap::new();
+
+        with_scratch(|scratch| {
+            requests.iter().map(|request| match *request {
+                LookupRequest::Class { class_name } => self.remap_class(class_name),
+                LookupRequest::Method { class_name, method_name, descriptor } => {
+                    let class_mapping = self.classes.get(class_name)?;
+                    let remapped_descriptor = descriptors.entry(descriptor)
+                        .or_insert_with(|| self.remap_descriptor_with(descriptor, scratch).to_string());
+
+                    class_mapping.methods.get(scratch.fill_key(method_name, remapped_descriptor, |_| None))
+                        .map(|method_mapping| method_mapping.official_name.clone().unwrap_or_else(|| method_name.to_string()))
+                }
+                LookupRequest::Field { class_name, field_name, descriptor } => {
+                    let class_mapping = self.classes.get(class_name)?;
+                    let remapped_descriptor = descriptors.entry(descriptor)
+                        .or_insert_with(|| self.remap_descriptor_with(descriptor, scratch).to_string());
+
+                    class_mapping.fields.get(scratch.fill_key(field_name, remapped_descriptor, |_| None))
+                        .map(|field_mapping| field_mapping.official_name.clone().unwrap_or_else(|| field_name.to_string()))
+                }
+            }).collect()
+        })
+    }
+
+}
+
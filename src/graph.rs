@@ -0,0 +1,109 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use crate::Mapping;
+
+// GraphFormat enum that selects the output format of `Mapping::export_class_graph`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    Dot,   // Graphviz digraph
+    Json,  // {"nodes": [...], "edges": [...]} adjacency list
+}
+
+// Node of the class graph, identified by its namespace and name.
+struct Node<'a> {
+    namespace: &'static str,
+    name: &'a str,
+}
+
+impl Node<'_> {
+
+    fn id(&self) -> String {
+        format!("{}:{}", self.namespace, self.name)
+    }
+
+}
+
+impl Mapping {
+
+    ///
+    /// Exports how the selected classes are linked across namespaces as a graph.
+    ///
+    /// Classes are selected by their named name, unknown and repeated names are skipped.
+    /// Every class contributes a node per namespace it has a name in, with edges
+    /// leading from named to intermediary to official. Nodes and edges keep the order of the selection.
+    ///
+    pub fn export_class_graph(&self, class_names: &[&str], format: GraphFormat) -> String {
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+        let mut seen = HashSet::new();
+
+        for &class_name in class_names {
+            if !seen.insert(class_name) {
+                continue;
+            }
+            let Some(class_mapping) = self.classes.get(class_name) else {
+                continue;
+            };
+
+            let chain: Vec<Node> = [
+                ("named", Some(class_name)),
+                ("intermediary", class_mapping.intermediary_name.as_deref()),
+                ("official", class_mapping.official_name.as_deref()),
+            ].into_iter()
+                .filter_map(|(namespace, name)| name.filter(|name| !name.is_empty()).map(|name| Node { namespace, name }))
+                .collect();
+
+            for pair in chain.windows(2) {
+                edges.push((pair[0].id(), pair[1].id()));
+            }
+            nodes.extend(chain);
+        }
+
+        match format {
+            GraphFormat::Dot => write_dot(&nodes, &edges),
+            GraphFormat::Json => write_json(&nodes, &edges),
+        }
+    }
+
+}
+
+fn write_dot(nodes: &[Node], edges: &[(String, String)]) -> String {
+    let mut dot = String::from("digraph classes {\n");
+    for node in nodes {
+        let _ = writeln!(dot, "    \"{}\" [label=\"{}\", namespace=\"{}\"];", escape(&node.id()), escape(node.name), node.namespace);
+    }
+    for (from, to) in edges {
+        let _ = writeln!(dot, "    \"{}\" -> \"{}\";", escape(from), escape(to));
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+fn write_json(nodes: &[Node], edges: &[(String, String)]) -> String {
+    let nodes: Vec<String> = nodes.iter()
+        .map(|node| format!("{{\"id\":\"{}\",\"namespace\":\"{}\",\"name\":\"{}\"}}", escape(&node.id()), node.namespace, escape(node.name)))
+        .collect();
+    let edges: Vec<String> = edges.iter()
+        .map(|(from, to)| format!("{{\"from\":\"{}\",\"to\":\"{}\"}}", escape(from), escape(to)))
+        .collect();
+
+    format!("{{\"nodes\":[{}],\"edges\":[{}]}}", nodes.join(","), edges.join(","))
+}
+
+/// Escapes a string for use inside a double quoted DOT or JSON string.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c if c.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
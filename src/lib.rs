@@ -1,10 +1,24 @@
-use anyhow::{Context, Result, bail};
+use anyhow::Result;
 use derive_new::new;
 use derive_getters::Getters;
 use std::collections::HashMap;
-use std::fs;
 use std::path::Path;
 
+mod batch;
+mod graph;
+mod limits;
+mod loader;
+mod log;
+mod scratch;
+mod table;
+
+pub use batch::{LookupRequest, LookupResult};
+pub use graph::GraphFormat;
+pub use limits::{LimitError, Limits};
+pub use loader::{ChunkedLoader, LoadStatus};
+pub use scratch::{Scratch, with_scratch};
+pub use table::TranslationTable;
+
 // Header struct that parses and stores header information of TinyV2 mapping.
 #[derive(Debug, new, Getters)]
 pub struct Header {
@@ -54,188 +68,89 @@ impl Mapping {
 
     /// Remaps the named method name to its obfuscated counterpart from the mapping data, given the descriptor.
     pub fn remap_method(&self, class_name: &str, method_name: &str, descriptor: &str) -> Option<String> {
-        let remapped_decriptor = self.remap_descriptor(descriptor);
-        
-        self.classes.get(class_name)
-            .and_then(|class_mapping| class_mapping.methods.get(&(method_name.to_string(), remapped_decriptor)))
-            .map(|method_mapping| method_mapping.official_name.clone().unwrap_or_else(|| method_name.to_string()))
+        let class_mapping = self.classes.get(class_name)?;
+
+        with_scratch(|scratch| {
+            class_mapping.methods.get(scratch.fill_key(method_name, descriptor, |class_name| self.official_class_name(class_name)))
+                .map(|method_mapping| method_mapping.official_name.clone().unwrap_or_else(|| method_name.to_string()))
+        })
     }
 
     /// Remaps the named field name to its obfuscated counterpart from the mapping data, given the descriptor.
     pub fn remap_field(&self, class_name: &str, field_name: &str, descriptor: &str) -> Option<String> {
-        let remapped_decriptor = self.remap_descriptor(descriptor);
+        let class_mapping = self.classes.get(class_name)?;
 
-        self.classes.get(class_name)
-            .and_then(|class_mapping| class_mapping.fields.get(&(field_name.to_string(), remapped_decriptor)))
-            .map(|field_mapping| field_mapping.official_name.clone().unwrap_or_else(|| field_name.to_string()))
+        with_scratch(|scratch| {
+            class_mapping.fields.get(scratch.fill_key(field_name, descriptor, |class_name| self.official_class_name(class_name)))
+                .map(|field_mapping| field_mapping.official_name.clone().unwrap_or_else(|| field_name.to_string()))
+        })
     }
 
     ///
     /// Remaps the named descriptor to its obfuscated counterpart from the mapping data.
     /// 
-    /// This function is recursive and will remap the descriptor recursively.
     /// Input descriptor must be in named format (e.g. Lnet/minecraft/client/MinecraftClient;)
     /// Output descriptor will be in official format (e.g. Lev;)
     /// 
     /// Method descriptor is also supported (e.g. (Lnet/minecraft/client/MinecraftClient;)V)
     /// 
     pub fn remap_descriptor(&self, descriptor: &str) -> String {
-        // Remap L class descriptor from named to official
-        if descriptor.starts_with('L') {
-            // Format: Lnet/minecraft/client/MinecraftClient;
-            let class_name = descriptor[1..descriptor.len()-1].to_string();
-            let remapped_class_name = self.remap_class(&class_name).unwrap_or_else(|| class_name.clone());
-            return format!("L{};", remapped_class_name);
-        }
-
-        // Remap [ array descriptor
-        if descriptor.starts_with('[') {
-            // Format: [Lnet/minecraft/client/MinecraftClient;
-            let remapped_descriptor = self.remap_descriptor(&descriptor[1..]);
-            return format!("[{}", remapped_descriptor);
-        }
-
-        // Remap ( method descriptor
-        if descriptor.starts_with('(') {
-            // Remap method descriptor recursively
-            // Format: (Lnet/minecraft/client/MinecraftClient;Lnet/minecraft/client/MinecraftClient;)Lnet/minecraft/client/MinecraftClient;
-
-            let mut remapped_descriptor = String::new();
-            let mut current_descriptor = String::new();
-
-            for c in descriptor.chars() {
-                if c == '(' {
-                    remapped_descriptor.push('(');
-                    continue;
-                }
-                if c == ')' {
-                    remapped_descriptor.push(')');
-                    continue;
-                }
-                if c == ';' {
-                    // Remap descriptor
-                    current_descriptor.push(';');
-                    let remapped_current_descriptor = self.remap_descriptor(&current_descriptor);
-                    remapped_descriptor.push_str(&remapped_current_descriptor);
-                    current_descriptor.clear();
-                    continue;
-                }
-                if c == 'L' {
-                    // Start of class descriptor
-                    current_descriptor.push('L');
-                    continue;
-                }
-
-                if current_descriptor.is_empty() {
-                    remapped_descriptor.push(c);
-                } else {
-                    current_descriptor.push(c);
-                }
-            }
-            
-            return remapped_descriptor;
-            
-        }
-        
-        return descriptor.to_string();
+        with_scratch(|scratch| self.remap_descriptor_with(descriptor, scratch).to_string())
     }
 
+    ///
+    /// Remaps the named descriptor like `remap_descriptor`, but writes the result into the given scratch buffers.
+    /// 
+    /// The returned descriptor borrows from `scratch` and is overwritten by the next call.
+    /// 
+    pub fn remap_descriptor_with<'a>(&self, descriptor: &str, scratch: &'a mut Scratch) -> &'a str {
+        let remapped_descriptor = &mut scratch.descriptor;
+        remapped_descriptor.clear();
 
-}
+        map_descriptor(descriptor, remapped_descriptor, |class_name| self.official_class_name(class_name));
 
-/// Parses a TinyV2 formatted input into a `Mapping` struct.
-pub fn parse_tiny_v2(file_path: &Path) -> Result<Mapping> {
-    let contents = fs::read_to_string(file_path)
-        .with_context(|| format!("Failed to read mapping file {:?}", file_path))?;
-    let mut lines = contents.lines();
-
-    let header_line = lines.next().context("Missing header line in mapping file")?;
-    let header_parts: Vec<&str> = header_line.split('\t').collect();
-    if header_parts[0] != "tiny" || header_parts.len() < 5 {
-        bail!("Invalid header format");
+        remapped_descriptor
     }
 
-    let major_version: usize = header_parts[1].parse()?;
-    let minor_version: usize = header_parts[2].parse()?;
-    let namespaces: Vec<String> = header_parts[3..].iter().map(|s| s.to_string()).collect();
-
-    let header = Header::new(major_version, minor_version, namespaces);
-    let mut mapping = Mapping::new(header);
-
-    let namespace_named_index = mapping.header.namespaces.iter().position(|ns| ns == "named")
-        .context("Failed to find namespace named")?;
-    let namespace_intermediary_index = mapping.header.namespaces.iter().position(|ns| ns == "intermediary")
-        .context("Failed to find namespace intermediary")?;
-    let namespace_official_index = mapping.header.namespaces.iter().position(|ns| ns == "official")
-        .context("Failed to find namespace official")?;
+    /// Returns the official name of a named class, if the mapping has one.
+    fn official_class_name(&self, class_name: &str) -> Option<&str> {
+        self.classes.get(class_name).and_then(|c| c.official_name.as_deref())
+    }
 
-    let mut current_class_name = String::new();
+}
 
-    // Parse the rest of the lines to populate classes, methods, and fields.
-    for line in lines {
-        if line.is_empty() || line.starts_with('#') {
-            continue; // Skip comments or empty lines.
-        }
-        let parts: Vec<&str> = line.split('\t').collect();
-        
-        match parts[0] {
-            "c" => {
-                // Class section
-                let class_name = parts.get(1 + namespace_named_index)
-                    .map(|s| s.to_string())
-                    .context("Named name not found for class")?;
-                let official_name = parts.get(1 + namespace_official_index)
-                    .map(|s| s.to_string());
-                let intermediary_name = parts.get(1 + namespace_intermediary_index)
-                    .map(|s| s.to_string());
-
-                current_class_name = class_name.clone();
-                mapping.classes.insert(class_name, ClassMapping::new(official_name, intermediary_name, HashMap::new(), HashMap::new()));
-            }
-            _ if parts[0].is_empty() && !parts[1].is_empty() => {
-                // Method or field section, tab indicates a subsection.
-                if let Some(class_mapping) = mapping.classes.get_mut(&current_class_name) {
-                    let subsection_type = &parts[1];
-                    let descriptor = parts[2].to_string();
-
-                    match *subsection_type {
-                        "m" => {
-                            let named_name = parts.get(3 + namespace_named_index)
-                                .context("Named name not found for method or field")?
-                                .to_string();
-                            let official_name = parts.get(3 + namespace_official_index)
-                                .map(|s| s.to_string());
-                            let intermediary_name = parts.get(3 + namespace_intermediary_index)
-                                .map(|s| s.to_string());
-
-                            // Method section
-                            class_mapping.methods.insert((named_name, descriptor), MethodMapping::new(official_name, intermediary_name));
-                        }
-                        "f" => {
-                            let named_name = parts.get(3 + namespace_named_index)
-                                .context("Named name not found for method or field")?
-                                .to_string();
-                            let official_name = parts.get(3 + namespace_official_index)
-                                .map(|s| s.to_string());
-                            let intermediary_name = parts.get(3 + namespace_intermediary_index)
-                                .map(|s| s.to_string());
-
-                            // Field section
-                            class_mapping.fields.insert((named_name, descriptor), FieldMapping::new(official_name, intermediary_name));
-                        }
-                        "c" => {
-                            // Comment section
-                            // Not relevant for remapping.
-                        }
-                        _ => bail!("Unknown subsection type"),
-                    }
-                }
-            }
-            _ => {},
-        }
+/// Writes the descriptor into `out`, replacing every class name that `map_class` knows.
+pub(crate) fn map_descriptor<'a>(descriptor: &'a str, out: &mut String, map_class: impl Fn(&'a str) -> Option<&'a str>) {
+    let mut rest = descriptor;
+    while let Some(start) = rest.find('L') {
+        // Copy primitives, array brackets and parentheses as they are
+        out.push_str(&rest[..=start]);
+        rest = &rest[start + 1..];
+
+        // Format: net/minecraft/client/MinecraftClient;
+        let Some(end) = rest.find(';') else {
+            break;
+        };
+        let class_name = &rest[..end];
+        out.push_str(map_class(class_name).unwrap_or(class_name));
+        rest = &rest[end..];
     }
+    out.push_str(rest);
+}
+
+/// Parses a TinyV2 formatted input into a `Mapping` struct.
+pub fn parse_tiny_v2(file_path: &Path) -> Result<Mapping> {
+    parse_tiny_v2_with_limits(file_path, &Limits::default())
+}
 
-    Ok(mapping)
+///
+/// Parses a TinyV2 formatted input into a `Mapping` struct, enforcing the given resource limits.
+/// 
+/// The file size is checked before the file is read, the line length and class count while parsing.
+/// Exceeding a limit fails with a `LimitError`.
+/// 
+pub fn parse_tiny_v2_with_limits(file_path: &Path, limits: &Limits) -> Result<Mapping> {
+    ChunkedLoader::with_limits(file_path, limits)?.finish()
 }
 
 #[cfg(test)]
@@ -246,6 +161,29 @@ mod tests {
         parse_tiny_v2(Path::new("mappings.tiny")).unwrap()
     }
 
+    // TempMapping struct that removes the written mapping file once the test is done with it.
+    struct TempMapping(std::path::PathBuf);
+
+    impl std::ops::Deref for TempMapping {
+        type Target = Path;
+
+        fn deref(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for TempMapping {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    fn write_mapping(name: &str, contents: &str) -> TempMapping {
+        let path = std::env::temp_dir().join(format!("yarn_remapper_{}_{}.tiny", std::process::id(), name));
+        std::fs::write(&path, contents).unwrap();
+        TempMapping(path)
+    }
+
     #[test]
     fn test_class_remap() {
         let mapping = get_mapping();
@@ -272,4 +210,180 @@ mod tests {
         assert_eq!(mapping.remap_field("net/minecraft/client/MinecraftClient", "inGameHud", "Lnet/minecraft/client/gui/hud/InGameHud;"), Some("l".to_string()));
     }
 
+    #[test]
+    fn test_descriptor_remap() {
+        let mapping = get_mapping();
+
+        assert_eq!(mapping.remap_descriptor("([Lnet/minecraft/client/MinecraftClient;ILjava/lang/String;)Lnet/minecraft/client/MinecraftClient;"), "([Levi;ILjava/lang/String;)Levi;");
+        assert_eq!(mapping.remap_descriptor("[[I"), "[[I");
+    }
+
+    #[test]
+    fn test_descriptor_remap_with_scratch() {
+        let mapping = get_mapping();
+        let mut scratch = Scratch::default();
+
+        assert_eq!(mapping.remap_descriptor_with("Lnet/minecraft/client/MinecraftClient;", &mut scratch), "Levi;");
+        assert_eq!(mapping.remap_descriptor_with("()V", &mut scratch), "()V");
+        assert_eq!(with_scratch(|scratch| mapping.remap_descriptor_with("[Lnet/minecraft/client/MinecraftClient;", scratch).to_string()), "[Levi;");
+    }
+
+    #[test]
+    fn test_limits() {
+        let path = write_mapping("limits", "tiny\t2\t0\tofficial\tintermediary\tnamed\nc\ta\tnet/minecraft/class_1\tnet/minecraft/A\nc\tb\tnet/minecraft/class_2\tnet/minecraft/B\n");
+        let limit_error = |limits: Limits| parse_tiny_v2_with_limits(&path, &limits).unwrap_err().downcast::<LimitError>().unwrap();
+
+        assert!(matches!(limit_error(Limits::new(Some(16), None, None)), LimitError::FileTooLarge { max: 16, .. }));
+        assert_eq!(limit_error(Limits::new(None, Some(1), None)), LimitError::TooManyClasses { max: 1 });
+        assert!(matches!(limit_error(Limits::new(None, None, Some(8))), LimitError::LineTooLong { line: 1, max: 8, .. }));
+        assert!(parse_tiny_v2_with_limits(&path, &Limits::new(Some(u64::MAX), Some(usize::MAX), Some(usize::MAX))).is_ok());
+    }
+
+    #[test]
+    fn test_class_graph_export() {
+        let mapping = get_mapping();
+        let class_names = ["net/minecraft/client/MinecraftClient", "net/minecraft/Missing", "net/minecraft/client/MinecraftClient"];
+
+        let dot = mapping.export_class_graph(&class_names, GraphFormat::Dot);
+        assert!(dot.starts_with("digraph classes {\n"));
+        assert!(dot.contains("\"named:net/minecraft/client/MinecraftClient\" -> \"intermediary:net/minecraft/class_310\";"));
+        assert!(dot.contains("\"intermediary:net/minecraft/class_310\" -> \"official:evi\";"));
+        assert_eq!(dot.matches(" -> ").count(), 2);
+
+        let json = mapping.export_class_graph(&class_names, GraphFormat::Json);
+        assert_eq!(json.matches("\"id\"").count(), 3);
+        assert!(json.contains("{\"from\":\"intermediary:net/minecraft/class_310\",\"to\":\"official:evi\"}"));
+    }
+
+    #[test]
+    fn test_translation_table() {
+        let mapping = get_mapping();
+
+        let table = mapping.compile("official", "named").unwrap();
+        assert_eq!((table.from_namespace(), table.to_namespace()), ("official", "named"));
+        assert_eq!(table.translate_class("evi"), Some("net/minecraft/client/MinecraftClient"));
+        assert_eq!(table.translate_method("evi", "be", "()Ljava/lang/String;"), Some("getWindowTitle"));
+        assert_eq!(table.translate_descriptor("(Levi;I)V"), "(Lnet/minecraft/client/MinecraftClient;I)V");
+
+        let table = mapping.compile("named", "intermediary").unwrap();
+        assert_eq!(table.translate_class("net/minecraft/client/MinecraftClient"), Some("net/minecraft/class_310"));
+        assert_eq!(table.translate_field("net/minecraft/client/MinecraftClient", "inGameHud", "Lnet/minecraft/client/gui/hud/InGameHud;"), Some("field_1705"));
+        assert_eq!(table.translate_class("evi"), None);
+
+        assert!(mapping.compile("named", "mojang").is_err());
+    }
+
+    #[test]
+    fn test_class_loading_log_translation() {
+        let table = get_mapping().compile("official", "named").unwrap();
+        let log = "[Loaded evi from file:/minecraft.jar]\n[0.512s][info][class,load] evi source: file:/minecraft.jar\n[Loaded java.lang.Object from jrt:/java.base]\n";
+
+        assert_eq!(table.translate_class_loading_log(log), "[Loaded net.minecraft.client.MinecraftClient from file:/minecraft.jar]\n[0.512s][info][class,load] net.minecraft.client.MinecraftClient source: file:/minecraft.jar\n[Loaded java.lang.Object from jrt:/java.base]\n");
+    }
+
+    #[test]
+    fn test_chunked_loader() {
+        let mut loader = ChunkedLoader::new(Path::new("mappings.tiny")).unwrap();
+        let initial_progress = loader.progress();
+
+        assert!(matches!(loader.poll(1).unwrap(), LoadStatus::Pending));
+        assert!(loader.progress() > initial_progress);
+
+        let mapping = loop {
+            if let LoadStatus::Done(mapping) = loader.poll(2).unwrap() {
+                break mapping;
+            }
+        };
+        assert_eq!(loader.progress(), 1.0);
+        assert!(loader.poll(1).is_err());
+
+        assert_eq!(mapping.remap_class("net/minecraft/client/MinecraftClient"), Some("evi".to_string()));
+        assert_eq!(mapping.classes().len(), get_mapping().classes().len());
+    }
+
+    #[test]
+    fn test_exception_message_translation() {
+        // Obfuscated names like be, to, in and app collide with regular words
+        let path = write_mapping("exceptions", "tiny\t2\t0\tofficial\tintermediary\tnamed
+c\tevi\tnet/minecraft/class_310\tnet/minecraft/client/MinecraftClient
+\tm\t(ILbe;)V\ta\tmethod_1\ttick
+\tf\tLeyu;\tl\tfield_1705\tinGameHud
+c\teyu\tnet/minecraft/class_329\tnet/minecraft/client/gui/hud/InGameHud
+c\tbe\tnet/minecraft/class_1\tnet/minecraft/util/Be
+c\tto\tnet/minecraft/class_2\tnet/minecraft/util/To
+c\tin\tnet/minecraft/class_3\tnet/minecraft/util/In
+c\tapp\tnet/minecraft/class_4\tnet/minecraft/util/App
+");
+        let table = parse_tiny_v2(&path).unwrap().compile("official", "named").unwrap();
+        let translate = |line: &str| table.translate_exception_messages(line);
+
+        assert_eq!(translate("java.lang.ClassCastException: class evi cannot be cast to class eyu (evi and eyu are in unnamed module of loader 'app')\n"),
+            "java.lang.ClassCastException: class net.minecraft.client.MinecraftClient cannot be cast to class net.minecraft.client.gui.hud.InGameHud (net.minecraft.client.MinecraftClient and net.minecraft.client.gui.hud.InGameHud are in unnamed module of loader 'app')\n");
+        assert_eq!(translate("java.lang.ClassCastException: class evi cannot be cast to class java.lang.String (evi is in unnamed module of loader 'app'; java.lang.String is in module java.base of loader 'bootstrap')"),
+            "java.lang.ClassCastException: class net.minecraft.client.MinecraftClient cannot be cast to class java.lang.String (net.minecraft.client.MinecraftClient is in unnamed module of loader 'app'; java.lang.String is in module java.base of loader 'bootstrap')");
+        assert_eq!(translate("java.lang.ClassCastException: evi cannot be cast to eyu"),
+            "java.lang.ClassCastException: net.minecraft.client.MinecraftClient cannot be cast to net.minecraft.client.gui.hud.InGameHud");
+        assert_eq!(translate("Caused by: java.lang.NoSuchMethodError: 'void evi.a(int, be)'"),
+            "Caused by: java.lang.NoSuchMethodError: 'void net.minecraft.client.MinecraftClient.tick(int, net.minecraft.util.Be)'");
+        assert_eq!(translate("java.lang.NoSuchFieldError: Class evi does not have member field 'eyu l'"),
+            "java.lang.NoSuchFieldError: Class net.minecraft.client.MinecraftClient does not have member field 'net.minecraft.client.gui.hud.InGameHud inGameHud'");
+        assert_eq!(translate("java.lang.IllegalAccessError: class to tried to access private field 'eyu evi.l' (to and evi are in unnamed module of loader 'app')"),
+            "java.lang.IllegalAccessError: class net.minecraft.util.To tried to access private field 'net.minecraft.client.gui.hud.InGameHud net.minecraft.client.MinecraftClient.inGameHud' (net.minecraft.util.To and net.minecraft.client.MinecraftClient are in unnamed module of loader 'app')");
+        assert_eq!(translate("java.lang.IllegalAccessError: can't access field 'eyu evi.l'"),
+            "java.lang.IllegalAccessError: can't access field 'net.minecraft.client.gui.hud.InGameHud net.minecraft.client.MinecraftClient.inGameHud'");
+        assert_eq!(translate("java.lang.IllegalAccessError: tried to access method 'static void evi.a(int, be)'"),
+            "java.lang.IllegalAccessError: tried to access method 'static void net.minecraft.client.MinecraftClient.tick(int, net.minecraft.util.Be)'");
+        assert_eq!(translate("java.lang.NoSuchMethodError: evi.a(ILbe;)V"),
+            "java.lang.NoSuchMethodError: net.minecraft.client.MinecraftClient.tick(ILnet/minecraft/util/Be;)V");
+        assert_eq!(translate("Caused by: java.lang.NoClassDefFoundError: to\n"), "Caused by: java.lang.NoClassDefFoundError: net.minecraft.util.To\n");
+
+        // Words outside of the known positions are left alone
+        for line in ["java.lang.IllegalStateException: Cannot be in state to app", "java.lang.IllegalStateException: in", "java.lang.NoSuchFieldError: 'app'",
+            "java.lang.NoSuchMethodError: 'a b c d(e,,f)'", "java.lang.IllegalStateException: Cannot be in state (to and app are required)",
+            "\tat evi.a(SourceFile:12)", "[Render thread/INFO]: class evi"] {
+            assert_eq!(translate(line), line);
+        }
+    }
+
+    #[test]
+    fn test_malformed_lines() {
+        let limits = Limits::new(Some(1024), Some(16), Some(256));
+        let header = "tiny\t2\t0\tofficial\tintermediary\tnamed\nc\ta\tnet/minecraft/class_1\tnet/minecraft/A\n";
+
+        for (name, line) in [("member", "\tm"), ("descriptor", "\tf\t"), ("subsection", "\tx\tI\ta\tfield_1\tb"), ("class", "c")] {
+            let path = write_mapping(name, &format!("{}{}\n", header, line));
+            assert!(parse_tiny_v2_with_limits(&path, &limits).is_err(), "{:?} should be rejected", line);
+        }
+
+        let path = write_mapping("header", "tiny\t2\n");
+        assert!(parse_tiny_v2_with_limits(&path, &limits).is_err());
+    }
+
+    #[test]
+    fn test_chunked_loader_poisoned_by_error() {
+        let path = write_mapping("poisoned", "tiny\t2\t0\tofficial\tintermediary\tnamed\nc\ta\tnet/minecraft/class_1\tnet/minecraft/A\n\tx\tI\ta\tfield_1\tb\nc\tb\tnet/minecraft/class_2\tnet/minecraft/B\n");
+        let mut loader = ChunkedLoader::new(&path).unwrap();
+
+        assert!(!loader.has_failed());
+        assert!(loader.poll(usize::MAX).is_err());
+        assert!(loader.has_failed());
+        assert!(loader.progress() < 1.0);
+        assert!(loader.poll(usize::MAX).is_err());
+        assert!(loader.finish().is_err());
+    }
+
+    #[test]
+    fn test_remap_many() {
+        let mapping = get_mapping();
+        let requests = [
+            LookupRequest::Class { class_name: "net/minecraft/client/MinecraftClient" },
+            LookupRequest::Method { class_name: "net/minecraft/client/MinecraftClient", method_name: "getWindowTitle", descriptor: "()Ljava/lang/String;" },
+            LookupRequest::Field { class_name: "net/minecraft/client/MinecraftClient", field_name: "inGameHud", descriptor: "Lnet/minecraft/client/gui/hud/InGameHud;" },
+            LookupRequest::Method { class_name: "net/minecraft/client/MinecraftClient", method_name: "missing", descriptor: "()Ljava/lang/String;" },
+            LookupRequest::Class { class_name: "net/minecraft/Missing" },
+        ];
+
+        assert_eq!(mapping.remap_many(&requests), vec![Some("evi".to_string()), Some("be".to_string()), Some("l".to_string()), None, None]);
+    }
+
 }
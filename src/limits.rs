@@ -0,0 +1,36 @@
+use derive_getters::Getters;
+use derive_new::new;
+use std::fmt;
+
+// Limits struct that bounds the resources a mapping file may use while it is loaded.
+// A limit of None means unlimited, which is also the default for every limit.
+#[derive(Debug, Default, Clone, Copy, new, Getters)]
+pub struct Limits {
+    max_file_size: Option<u64>,      // In bytes
+    max_classes: Option<usize>,
+    max_line_length: Option<usize>,  // In bytes, without the line terminator
+}
+
+// LimitError enum that describes which limit a mapping file exceeded.
+// It is returned inside `anyhow::Error` and can be recovered with `downcast_ref`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LimitError {
+    FileTooLarge { size: u64, max: u64 },
+    TooManyClasses { max: usize },
+    LineTooLong { line: usize, length: usize, max: usize },
+}
+
+impl fmt::Display for LimitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LimitError::FileTooLarge { size, max } =>
+                write!(f, "Mapping file is {} bytes, exceeding the limit of {} bytes", size, max),
+            LimitError::TooManyClasses { max } =>
+                write!(f, "Mapping file contains more than {} classes", max),
+            LimitError::LineTooLong { line, length, max } =>
+                write!(f, "Line {} is {} bytes long, exceeding the limit of {} bytes", line, length, max),
+        }
+    }
+}
+
+impl std::error::Error for LimitError {}
@@ -0,0 +1,293 @@
+use anyhow::{Context, Result, bail};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use crate::{ClassMapping, FieldMapping, Header, LimitError, Limits, Mapping, MethodMapping};
+
+// LoadStatus enum that reports whether a `ChunkedLoader` has parsed the whole mapping yet.
+#[derive(Debug)]
+pub enum LoadStatus {
+    Pending,
+    Done(Mapping),
+}
+
+///
+/// Loads a TinyV2 mapping a few lines at a time.
+///
+/// Call `poll` with the number of lines to parse from an event loop until it returns `LoadStatus::Done`.
+/// The file is read and the header is parsed when the loader is created.
+///
+#[derive(Debug)]
+pub struct ChunkedLoader {
+    contents: String,
+    length: usize,       // Byte length of the file, kept after the contents are freed
+    position: usize,     // Byte offset of the next line
+    line_number: usize,  // Number of the last parsed line
+    limits: Limits,
+    parser: Option<LineParser>,
+    failed: bool,
+}
+
+impl ChunkedLoader {
+
+    /// Reads the mapping file and parses its header.
+    pub fn new(file_path: &Path) -> Result<Self> {
+        Self::with_limits(file_path, &Limits::default())
+    }
+
+    /// Reads the mapping file and parses its header, enforcing the given resource limits.
+    pub fn with_limits(file_path: &Path, limits: &Limits) -> Result<Self> {
+        let contents = read_mapping_file(file_path, limits)
+            .with_context(|| format!("Failed to read mapping file {:?}", file_path))?;
+
+        let mut loader = ChunkedLoader {
+            length: contents.len(),
+            contents,
+            position: 0,
+            line_number: 0,
+            limits: *limits,
+            parser: None,
+            failed: false,
+        };
+
+        let (header_line, position) = next_line(&loader.contents, loader.position)
+            .context("Missing header line in mapping file")?;
+        check_line_length(1, header_line, limits)?;
+        let parser = LineParser::from_header(header_line)?;
+
+        loader.position = position;
+        loader.line_number = 1;
+        loader.parser = Some(parser);
+        Ok(loader)
+    }
+
+    ///
+    /// Parses up to `lines` further lines of the mapping.
+    ///
+    /// Returns `LoadStatus::Done` with the mapping once the end of the file is reached.
+    /// Polling again after that fails, as does polling again after a line failed to parse,
+    /// so a partially parsed mapping is never returned.
+    ///
+    pub fn poll(&mut self, lines: usize) -> Result<LoadStatus> {
+        let Some(parser) = self.parser.as_mut() else {
+            bail!("Mapping has already been loaded or failed to load");
+        };
+
+        for _ in 0..lines {
+            let Some((line, position)) = next_line(&self.contents, self.position) else {
+                break;
+            };
+
+            let result = check_line_length(self.line_number + 1, line, &self.limits)
+                .and_then(|_| parser.parse_line(line, &self.limits));
+            if let Err(error) = result {
+                // Poison the loader, the mapping is incomplete without this line.
+                self.parser = None;
+                self.contents = String::new();
+                self.failed = true;
+                return Err(error);
+            }
+
+            self.position = position;
+            self.line_number += 1;
+        }
+
+        if self.position < self.contents.len() {
+            return Ok(LoadStatus::Pending);
+        }
+
+        self.contents = String::new(); // Free the file contents, they are no longer needed.
+        let parser = self.parser.take().context("Mapping has already been loaded or failed to load")?;
+        Ok(LoadStatus::Done(parser.mapping))
+    }
+
+    /// Parses the remaining lines and returns the mapping.
+    pub fn finish(mut self) -> Result<Mapping> {
+        match self.poll(usize::MAX)? {
+            LoadStatus::Done(mapping) => Ok(mapping),
+            LoadStatus::Pending => bail!("Mapping did not finish loading"),
+        }
+    }
+
+    ///
+    /// Returns the share of the file that has been parsed so far, from 0.0 to 1.0.
+    ///
+    /// Only a loaded mapping reports 1.0. After a line failed to parse, the progress stays at
+    /// the share parsed before that line, use `has_failed` to tell a failed loader from a pending one.
+    ///
+    pub fn progress(&self) -> f32 {
+        if self.length == 0 || (self.parser.is_none() && !self.failed) {
+            return 1.0;
+        }
+        self.position as f32 / self.length as f32
+    }
+
+    /// Returns whether a line failed to parse, which leaves the loader unable to finish.
+    pub fn has_failed(&self) -> bool {
+        self.failed
+    }
+
+}
+
+// LineParser struct that holds the parsing state between lines.
+#[derive(Debug)]
+struct LineParser {
+    mapping: Mapping,
+    namespace_named_index: usize,
+    namespace_intermediary_index: usize,
+    namespace_official_index: usize,
+    current_class_name: String,
+}
+
+impl LineParser {
+
+    fn from_header(header_line: &str) -> Result<Self> {
+        let header_parts: Vec<&str> = header_line.split('\t').collect();
+        if header_parts.first() != Some(&"tiny") || header_parts.len() < 5 {
+            bail!("Invalid header format");
+        }
+
+        let major_version: usize = header_parts.get(1).context("Major version not found in header")?.parse()?;
+        let minor_version: usize = header_parts.get(2).context("Minor version not found in header")?.parse()?;
+        let namespaces: Vec<String> = header_parts.iter().skip(3).map(|s| s.to_string()).collect();
+
+        let header = Header::new(major_version, minor_version, namespaces);
+        let mapping = Mapping::new(header);
+
+        let namespace_named_index = mapping.header.namespaces.iter().position(|ns| ns == "named")
+            .context("Failed to find namespace named")?;
+        let namespace_intermediary_index = mapping.header.namespaces.iter().position(|ns| ns == "intermediary")
+            .context("Failed to find namespace intermediary")?;
+        let namespace_official_index = mapping.header.namespaces.iter().position(|ns| ns == "official")
+            .context("Failed to find namespace official")?;
+
+        Ok(LineParser {
+            mapping,
+            namespace_named_index,
+            namespace_intermediary_index,
+            namespace_official_index,
+            current_class_name: String::new(),
+        })
+    }
+
+    /// Parses a single line after the header to populate classes, methods, and fields.
+    fn parse_line(&mut self, line: &str, limits: &Limits) -> Result<()> {
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(()); // Skip comments or empty lines.
+        }
+        let parts: Vec<&str> = line.split('\t').collect();
+        let section_type = parts.first().context("Section type not found")?;
+        let subsection_type = parts.get(1).filter(|subsection_type| !subsection_type.is_empty());
+
+        match (*section_type, subsection_type) {
+            ("c", _) => {
+                // Class section
+                let class_name = parts.get(1 + self.namespace_named_index)
+                    .map(|s| s.to_string())
+                    .context("Named name not found for class")?;
+                let official_name = parts.get(1 + self.namespace_official_index)
+                    .map(|s| s.to_string());
+                let intermediary_name = parts.get(1 + self.namespace_intermediary_index)
+                    .map(|s| s.to_string());
+
+                if let Some(max) = *limits.max_classes() {
+                    if self.mapping.classes.len() >= max {
+                        return Err(LimitError::TooManyClasses { max }.into());
+                    }
+                }
+
+                self.current_class_name = class_name.clone();
+                self.mapping.classes.insert(class_name, ClassMapping::new(official_name, intermediary_name, HashMap::new(), HashMap::new()));
+            }
+            ("", Some(subsection_type)) => {
+                // Method or field section, tab indicates a subsection.
+                if let Some(class_mapping) = self.mapping.classes.get_mut(&self.current_class_name) {
+                    let descriptor = parts.get(2)
+                        .context("Descriptor not found for method or field")?;
+
+                    match *subsection_type {
+                        "m" => {
+                            let named_name = parts.get(3 + self.namespace_named_index)
+                                .context("Named name not found for method or field")?
+                                .to_string();
+                            let official_name = parts.get(3 + self.namespace_official_index)
+                                .map(|s| s.to_string());
+                            let intermediary_name = parts.get(3 + self.namespace_intermediary_index)
+                                .map(|s| s.to_string());
+
+                            // Method section
+                            class_mapping.methods.insert((named_name, descriptor.to_string()), MethodMapping::new(official_name, intermediary_name));
+                        }
+                        "f" => {
+                            let named_name = parts.get(3 + self.namespace_named_index)
+                                .context("Named name not found for method or field")?
+                                .to_string();
+                            let official_name = parts.get(3 + self.namespace_official_index)
+                                .map(|s| s.to_string());
+                            let intermediary_name = parts.get(3 + self.namespace_intermediary_index)
+                                .map(|s| s.to_string());
+
+                            // Field section
+                            class_mapping.fields.insert((named_name, descriptor.to_string()), FieldMapping::new(official_name, intermediary_name));
+                        }
+                        "c" => {
+                            // Comment section
+                            // Not relevant for remapping.
+                        }
+                        _ => bail!("Unknown subsection type"),
+                    }
+                }
+            }
+            _ => {},
+        }
+
+        Ok(())
+    }
+
+}
+
+/// Returns the line starting at `position` without its terminator, and the position of the line after it.
+fn next_line(contents: &str, position: usize) -> Option<(&str, usize)> {
+    let rest = contents.get(position..).filter(|rest| !rest.is_empty())?;
+    let (line, next_position) = match rest.find('\n') {
+        Some(end) => (&rest[..end], position + end + 1),
+        None => (rest, contents.len()),
+    };
+    Some((line.strip_suffix('\r').unwrap_or(line), next_position))
+}
+
+/// Reads the whole mapping file, refusing files larger than the configured limit.
+fn read_mapping_file(file_path: &Path, limits: &Limits) -> Result<String> {
+    let mut file = File::open(file_path)?;
+    let mut contents = String::new();
+
+    match *limits.max_file_size() {
+        Some(max) => {
+            let size = file.metadata()?.len();
+            if size > max {
+                return Err(LimitError::FileTooLarge { size, max }.into());
+            }
+
+            // The file might grow after the size check, so never read more than the limit allows.
+            file.by_ref().take(max.saturating_add(1)).read_to_string(&mut contents)?;
+            if contents.len() as u64 > max {
+                return Err(LimitError::FileTooLarge { size: contents.len() as u64, max }.into());
+            }
+        }
+        None => {
+            file.read_to_string(&mut contents)?;
+        }
+    }
+
+    Ok(contents)
+}
+
+/// Fails if the line is longer than the configured line length limit.
+fn check_line_length(line_number: usize, line: &str, limits: &Limits) -> Result<()> {
+    match *limits.max_line_length() {
+        Some(max) if line.len() > max => Err(LimitError::LineTooLong { line: line_number, length: line.len(), max }.into()),
+        _ => Ok(()),
+    }
+}
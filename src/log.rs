@@ -0,0 +1,333 @@
+use crate::TranslationTable;
+
+// Markers that precede the class name in JVM class loading output.
+const CLASS_LOADING_MARKERS: [&str; 4] = [
+    "[Loaded ",                         // Java 8: [Loaded evi from file:/...]
+    "[Loading ",                        // Java 8: [Loading evi from file:/...]
+    "[class,load] ",                    // Java 9+: [0.123s][info][class,load] evi source: file:/...
+    "[class,unload] unloading class ",  // Java 9+: [0.123s][info][class,unload] unloading class evi 0x...
+];
+
+impl TranslationTable {
+
+    ///
+    /// Translates the class names of `-verbose:class` / `-Xlog:class+load` output.
+    ///
+    /// Class names are expected in binary form (e.g. net.minecraft.class_310) and are written back the same way.
+    /// Lines that are not class loading output, or whose class is unknown, are kept as they are.
+    ///
+    pub fn translate_class_loading_log(&self, log: &str) -> String {
+        let mut translated_log = String::with_capacity(log.len());
+
+        for line in log.split_inclusive('\n') {
+            match class_token_range(line) {
+                Some((start, end)) => {
+                    let class_name = line[start..end].replace('.', "/");
+
+                    match self.translate_class(&class_name) {
+                        Some(translated_class_name) => {
+                            translated_log.push_str(&line[..start]);
+                            translated_log.push_str(&translated_class_name.replace('/', "."));
+                            translated_log.push_str(&line[end..]);
+                        }
+                        None => translated_log.push_str(line),
+                    }
+                }
+                None => translated_log.push_str(line),
+            }
+        }
+
+        translated_log
+    }
+
+    ///
+    /// Translates obfuscated names inside exception messages (e.g. "class evi cannot be cast to class eyu").
+    ///
+    /// Only lines containing "Exception: " or "Error: " are touched, and inside them only names in known positions:
+    /// - the whole message of NoClassDefFoundError, ClassNotFoundException, NoSuchMethodError and NoSuchFieldError
+    ///   if it is a single class or member reference, e.g. "NoClassDefFoundError: evi" or "NoSuchMethodError: evi.a(I)V"
+    /// - the word after "class " or "Class ", e.g. "class evi cannot be cast to class eyu"
+    /// - both operands of "X cannot be cast to Y"
+    /// - the classes of module clauses, e.g. "(evi and eyu are in unnamed module ...)" or "; eyu is in module ..."
+    /// - quoted members of NoSuchMethodError, NoSuchFieldError and IllegalAccessError messages that open the message
+    ///   or follow "method " or "field ", e.g. 'void evi.a(int)' or "Class evi does not have member field 'eyu l'"
+    ///
+    /// Members are translated using the descriptor built from the types in the message, and are only rewritten
+    /// if their owner, name or one of their types is known. Other words are never looked up, since short
+    /// obfuscated names collide with regular words.
+    ///
+    pub fn translate_exception_messages(&self, log: &str) -> String {
+        let mut translated_log = String::with_capacity(log.len());
+
+        for line in log.split_inclusive('\n') {
+            match exception_message_start(line) {
+                Some(start) => translated_log.push_str(&self.translate_exception_line(line, start)),
+                None => translated_log.push_str(line),
+            }
+        }
+
+        translated_log
+    }
+
+    /// Translates the names in known positions of a single exception line whose message begins at `start`.
+    fn translate_exception_line(&self, line: &str, start: usize) -> String {
+        let mut replacements: Vec<(usize, usize, String)> = Vec::new();
+        let message = line[start..].trim_end();
+        let message_end = start + message.len();
+
+        // The whole message is a single name: "java.lang.NoClassDefFoundError: evi"
+        let single_name_error = line[..start].strip_suffix(": ")
+            .is_some_and(|exception| SINGLE_NAME_ERRORS.iter().any(|error| exception.ends_with(error)));
+        if single_name_error && !message.is_empty() && !message.contains(char::is_whitespace) {
+            if let Some(translated) = self.translate_member_reference(message).or_else(|| self.translate_class_word(message)) {
+                replacements.push((start, message_end, translated));
+            }
+        }
+
+        // "class evi cannot be cast to class eyu", "Could not initialize class evi", "Class evi does not have member field"
+        for anchor in ["class ", "Class "] {
+            for (index, _) in line[start..].match_indices(anchor) {
+                let anchor_start = start + index;
+                if line[..anchor_start].ends_with(|c: char| c.is_alphanumeric()) {
+                    continue; // e.g. "subclass "
+                }
+                replacements.extend(self.translate_word_at(line, anchor_start + anchor.len()));
+            }
+        }
+
+        // "evi cannot be cast to eyu"
+        const CAST: &str = " cannot be cast to ";
+        for (index, _) in line[start..].match_indices(CAST) {
+            let operand_end = start + index;
+            let operand_start = line[..operand_end].trim_end_matches(is_class_name_char).len();
+            replacements.extend(self.translate_word_at(&line[..operand_end], operand_start));
+            replacements.extend(self.translate_word_at(line, operand_end + CAST.len()));
+        }
+
+        // "(evi and eyu are in unnamed module of loader 'app')", "(evi is in unnamed module of loader 'app'; eyu is in module java.base ...)"
+        for clause in ["(", "; "] {
+            for (index, _) in line[start..].match_indices(clause) {
+                let Some((first_start, first_end)) = word_at(line, start + index + clause.len()) else {
+                    continue;
+                };
+                if line[first_end..].starts_with(" is in ") {
+                    replacements.extend(self.translate_word_at(line, first_start));
+                } else if line[first_end..].starts_with(" and ") {
+                    let Some((second_start, second_end)) = word_at(line, first_end + " and ".len()) else {
+                        continue;
+                    };
+                    if line[second_end..].starts_with(" are in ") {
+                        replacements.extend(self.translate_word_at(line, first_start));
+                        replacements.extend(self.translate_word_at(line, second_start));
+                    }
+                }
+            }
+        }
+
+        // "'void evi.a(int)'" and "Class evi does not have member field 'eyu l'"
+        if MEMBER_ERRORS.iter().any(|error| line.contains(error)) {
+            let owner = line.find("Class ")
+                .and_then(|index| word_at(line, index + "Class ".len()))
+                .map(|(word_start, word_end)| &line[word_start..word_end]);
+
+            // Quotes are only paired after a known prefix, a stray apostrophe like in "can't" would shift them otherwise
+            let mut opens: Vec<usize> = ["method '", "field '"].iter()
+                .flat_map(|prefix| line[start..].match_indices(prefix).map(move |(index, _)| start + index + prefix.len()))
+                .collect();
+            if message.starts_with('\'') {
+                opens.push(start + 1);
+            }
+
+            for open in opens {
+                let Some(close) = line[open..].find('\'').map(|index| open + index) else {
+                    continue;
+                };
+                if let Some(translated) = self.translate_quoted_member(&line[open..close], owner) {
+                    replacements.push((open, close, translated));
+                }
+            }
+        }
+
+        // Apply the replacements front to back, the first of two overlapping replacements wins
+        replacements.sort_by_key(|(replacement_start, replacement_end, _)| (*replacement_start, *replacement_end));
+        let mut translated_line = String::with_capacity(line.len());
+        let mut position = 0;
+        for (replacement_start, replacement_end, translated) in replacements {
+            if replacement_start < position {
+                continue;
+            }
+            translated_line.push_str(&line[position..replacement_start]);
+            translated_line.push_str(&translated);
+            position = replacement_end;
+        }
+        translated_line.push_str(&line[position..]);
+
+        translated_line
+    }
+
+    /// Translates the class name starting at `word_start`, returning the replaced range and its translation.
+    fn translate_word_at(&self, line: &str, word_start: usize) -> Option<(usize, usize, String)> {
+        let (word_start, word_end) = word_at(line, word_start)?;
+        self.translate_class_word(&line[word_start..word_end])
+            .map(|translated| (word_start, word_end, translated))
+    }
+
+    /// Translates a known class name, keeping slashes but otherwise preferring binary names.
+    fn translate_class_word(&self, word: &str) -> Option<String> {
+        let translated_class_name = self.translate_class(&word.replace('.', "/"))?;
+
+        if word.contains('/') {
+            Some(translated_class_name.to_string())
+        } else {
+            Some(translated_class_name.replace('/', "."))
+        }
+    }
+
+    /// Translates a Java source type such as "evi" or "evi[]", returning None for primitives and unknown classes.
+    fn translate_java_type(&self, java_type: &str) -> Option<String> {
+        let element_type = java_type.trim_end_matches("[]");
+        let dimensions = &java_type[element_type.len()..];
+
+        self.translate_class_word(element_type).map(|translated| translated + dimensions)
+    }
+
+    /// Translates a Java 8 style member reference such as "evi.a(ILbe;)V".
+    fn translate_member_reference(&self, reference: &str) -> Option<String> {
+        let (qualified_name, descriptor) = reference.find('(').map(|index| reference.split_at(index))?;
+        let (owner, name) = qualified_name.rsplit_once('.')?;
+
+        let translated_name = self.translate_method(&owner.replace('.', "/"), name, descriptor).unwrap_or(name);
+        let translated_owner = self.translate_class_word(owner).unwrap_or_else(|| owner.to_string());
+        let translated = format!("{}.{}{}", translated_owner, translated_name, self.translate_descriptor(descriptor));
+
+        (translated != reference).then_some(translated)
+    }
+
+    /// Translates a quoted member such as "void evi.a(int, be)" or "eyu l", falling back to `owner` if it is not qualified.
+    fn translate_quoted_member(&self, member: &str, owner: Option<&str>) -> Option<String> {
+        let (head, parameters) = match member.find('(') {
+            Some(index) => (&member[..index], Some(&member[index..])),
+            None => (member, None),
+        };
+        let (type_prefix, qualified_name) = head.rsplit_once(' ')?;
+        // The member type may follow modifiers, e.g. "abstract void"
+        let (modifiers, member_type) = type_prefix.rsplit_once(' ')
+            .map_or(("", type_prefix), |(modifiers, member_type)| (&member[..modifiers.len() + 1], member_type));
+        let (owner, name) = match qualified_name.rsplit_once('.') {
+            Some((owner, name)) => (Some(owner), name),
+            None => (owner, qualified_name),
+        };
+
+        let parameter_types: Option<Vec<&str>> = parameters.map(|parameters| {
+            parameters.trim_start_matches('(').trim_end_matches(')')
+                .split(',')
+                .map(str::trim)
+                .filter(|parameter| !parameter.is_empty())
+                .collect()
+        });
+
+        let translated_name = owner.and_then(|owner| {
+            let owner = owner.replace('.', "/");
+            match &parameter_types {
+                Some(parameter_types) => {
+                    let descriptor = format!("({}){}",
+                        parameter_types.iter().map(|parameter| java_type_descriptor(parameter)).collect::<String>(),
+                        java_type_descriptor(member_type));
+                    self.translate_method(&owner, name, &descriptor)
+                }
+                None => self.translate_field(&owner, name, &java_type_descriptor(member_type)),
+            }
+        });
+        let translated_type = self.translate_java_type(member_type);
+        let qualified_owner = qualified_name.rsplit_once('.').map(|(qualified_owner, _)| qualified_owner);
+        let translated_owner = qualified_owner.and_then(|qualified_owner| self.translate_class_word(qualified_owner));
+        let translated_parameters: Option<Vec<Option<String>>> = parameter_types.as_ref()
+            .map(|parameter_types| parameter_types.iter().map(|parameter| self.translate_java_type(parameter)).collect());
+
+        // Leave the member as it is unless something in it is known, rebuilding it would only normalize its spacing
+        let resolved = translated_name.is_some() || translated_type.is_some() || translated_owner.is_some()
+            || translated_parameters.iter().flatten().any(Option::is_some);
+        if !resolved {
+            return None;
+        }
+
+        let mut translated = format!("{}{} ", modifiers, translated_type.as_deref().unwrap_or(member_type));
+        if let Some(qualified_owner) = qualified_owner {
+            translated.push_str(translated_owner.as_deref().unwrap_or(qualified_owner));
+            translated.push('.');
+        }
+        translated.push_str(translated_name.unwrap_or(name));
+        if let (Some(parameter_types), Some(translated_parameters)) = (&parameter_types, &translated_parameters) {
+            let parameters: Vec<&str> = parameter_types.iter().zip(translated_parameters)
+                .map(|(parameter, translated_parameter)| translated_parameter.as_deref().unwrap_or(parameter))
+                .collect();
+            translated.push_str(&format!("({})", parameters.join(", ")));
+        }
+
+        Some(translated)
+    }
+
+}
+
+// Errors whose whole message may be a single class or member reference.
+const SINGLE_NAME_ERRORS: [&str; 4] = ["NoClassDefFoundError", "ClassNotFoundException", "NoSuchMethodError", "NoSuchFieldError"];
+
+// Errors whose messages quote members as 'type owner.name(parameters)' or 'type name'.
+const MEMBER_ERRORS: [&str; 3] = ["NoSuchMethodError", "NoSuchFieldError", "IllegalAccessError"];
+
+/// Finds where the message of an exception line begins, after the first "Exception: " or "Error: ".
+fn exception_message_start(line: &str) -> Option<usize> {
+    ["Exception: ", "Error: "].iter()
+        .filter_map(|marker| line.find(marker).map(|index| index + marker.len()))
+        .min()
+}
+
+/// Finds the class name starting at `word_start`, without a trailing sentence dot.
+fn word_at(line: &str, word_start: usize) -> Option<(usize, usize)> {
+    let word_length = line[word_start..].find(|c: char| !is_class_name_char(c)).unwrap_or(line.len() - word_start);
+    let word = line[word_start..word_start + word_length].trim_end_matches('.');
+
+    (!word.is_empty()).then_some((word_start, word_start + word.len()))
+}
+
+fn is_class_name_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '$' | '.' | '/')
+}
+
+/// Converts a Java source type such as "int" or "java.lang.String[]" into a descriptor.
+fn java_type_descriptor(java_type: &str) -> String {
+    let element_type = java_type.trim_end_matches("[]");
+    let mut descriptor = "[".repeat((java_type.len() - element_type.len()) / 2);
+
+    match element_type {
+        "void" => descriptor.push('V'),
+        "boolean" => descriptor.push('Z'),
+        "byte" => descriptor.push('B'),
+        "char" => descriptor.push('C'),
+        "short" => descriptor.push('S'),
+        "int" => descriptor.push('I'),
+        "long" => descriptor.push('J'),
+        "float" => descriptor.push('F'),
+        "double" => descriptor.push('D'),
+        class_name => {
+            descriptor.push('L');
+            descriptor.push_str(&class_name.replace('.', "/"));
+            descriptor.push(';');
+        }
+    }
+
+    descriptor
+}
+
+/// Finds the byte range of the class name in a class loading line.
+fn class_token_range(line: &str) -> Option<(usize, usize)> {
+    CLASS_LOADING_MARKERS.iter().find_map(|marker| {
+        let marker_start = line.find(marker)?;
+        let start = marker_start + marker.len();
+        let start = start + (line[start..].len() - line[start..].trim_start().len());
+        let end = line[start..].find(|c: char| c.is_whitespace() || c == ']')
+            .map_or(line.len(), |end| start + end);
+
+        (start < end).then_some((start, end))
+    })
+}
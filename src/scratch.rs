@@ -0,0 +1,50 @@
+use std::cell::RefCell;
+
+use crate::map_descriptor;
+
+// Scratch struct that holds reusable buffers for the descriptor remapping and lookup hot paths.
+#[derive(Debug, Default)]
+pub struct Scratch {
+    pub(crate) descriptor: String,
+    pub(crate) key: (String, String),  // Reused (name, descriptor) lookup key
+}
+
+impl Scratch {
+
+    ///
+    /// Overwrites the reused lookup key without giving up its allocations.
+    ///
+    /// The descriptor is written through `map_class`, which returns the replacement for a class name
+    /// or None to keep it. Already remapped descriptors can pass `|_| None` to copy them as they are.
+    ///
+    pub(crate) fn fill_key<'a>(&mut self, name: &str, descriptor: &'a str, map_class: impl Fn(&'a str) -> Option<&'a str>) -> &(String, String) {
+        let key = &mut self.key;
+        key.0.clear();
+        key.0.push_str(name);
+        key.1.clear();
+        map_descriptor(descriptor, &mut key.1, map_class);
+        key
+    }
+
+}
+
+thread_local! {
+    static SCRATCH: RefCell<Scratch> = RefCell::new(Scratch::default());
+}
+
+///
+/// Runs the given closure with the scratch buffers of the current thread.
+///
+/// The buffers keep their capacity between calls, so repeated descriptor remapping and
+/// member lookups do not allocate once they have grown large enough.
+/// Nested calls get a fresh `Scratch` instead of panicking on the borrowed one.
+///
+/// Embedders with their own threading model can also keep a `Scratch` per worker
+/// and pass it to `Mapping::remap_descriptor_with` directly.
+///
+pub fn with_scratch<R>(f: impl FnOnce(&mut Scratch) -> R) -> R {
+    SCRATCH.with(|scratch| match scratch.try_borrow_mut() {
+        Ok(mut scratch) => f(&mut scratch),
+        Err(_) => f(&mut Scratch::default()),
+    })
+}
@@ -0,0 +1,165 @@
+use anyhow::{Result, bail};
+use std::collections::HashMap;
+
+use crate::{ClassMapping, Mapping, map_descriptor};
+
+// Namespace enum of the namespaces a `Mapping` keeps names for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Namespace {
+    Official,
+    Intermediary,
+    Named,
+}
+
+impl Namespace {
+
+    fn parse(namespace: &str) -> Result<Self> {
+        match namespace {
+            "official" => Ok(Namespace::Official),
+            "intermediary" => Ok(Namespace::Intermediary),
+            "named" => Ok(Namespace::Named),
+            _ => bail!("Unknown namespace {}", namespace),
+        }
+    }
+
+    /// Picks the name of this namespace, treating missing and empty names as unmapped.
+    fn pick<'a>(self, named: &'a str, official: &'a Option<String>, intermediary: &'a Option<String>) -> Option<&'a str> {
+        let name = match self {
+            Namespace::Official => official.as_deref(),
+            Namespace::Intermediary => intermediary.as_deref(),
+            Namespace::Named => Some(named),
+        };
+        name.filter(|name| !name.is_empty())
+    }
+
+    fn class_name(self, class_name: &str, class_mapping: &ClassMapping) -> Option<String> {
+        self.pick(class_name, &class_mapping.official_name, &class_mapping.intermediary_name).map(str::to_string)
+    }
+
+}
+
+// TranslationTable struct that holds a read-only translation between exactly two namespaces.
+// Every table is sorted by its source column and searched with a binary search.
+#[derive(Debug)]
+pub struct TranslationTable {
+    from_namespace: String,
+    to_namespace: String,
+    classes: Vec<(String, String)>,                     // (from class, to class)
+    methods: Vec<((String, String, String), String)>,   // ((from owner, from name, from descriptor), to name)
+    fields: Vec<((String, String, String), String)>,    // ((from owner, from name, from descriptor), to name)
+}
+
+impl TranslationTable {
+
+    /// Returns the namespace the table translates from.
+    pub fn from_namespace(&self) -> &str {
+        &self.from_namespace
+    }
+
+    /// Returns the namespace the table translates to.
+    pub fn to_namespace(&self) -> &str {
+        &self.to_namespace
+    }
+
+    /// Translates the class name from the source namespace to the target namespace.
+    pub fn translate_class(&self, class_name: &str) -> Option<&str> {
+        self.classes.binary_search_by(|(from, _)| from.as_str().cmp(class_name))
+            .ok()
+            .map(|index| self.classes[index].1.as_str())
+    }
+
+    /// Translates the method name from the source namespace to the target namespace, given the source owner and descriptor.
+    pub fn translate_method(&self, class_name: &str, method_name: &str, descriptor: &str) -> Option<&str> {
+        find_member(&self.methods, class_name, method_name, descriptor)
+    }
+
+    /// Translates the field name from the source namespace to the target namespace, given the source owner and descriptor.
+    pub fn translate_field(&self, class_name: &str, field_name: &str, descriptor: &str) -> Option<&str> {
+        find_member(&self.fields, class_name, field_name, descriptor)
+    }
+
+    /// Translates every class name inside a field or method descriptor from the source namespace to the target namespace.
+    pub fn translate_descriptor(&self, descriptor: &str) -> String {
+        let mut translated_descriptor = String::with_capacity(descriptor.len());
+        map_descriptor(descriptor, &mut translated_descriptor, |class_name| self.translate_class(class_name));
+        translated_descriptor
+    }
+
+}
+
+fn find_member<'a>(members: &'a [((String, String, String), String)], class_name: &str, name: &str, descriptor: &str) -> Option<&'a str> {
+    members.binary_search_by(|((owner, member, desc), _)| (owner.as_str(), member.as_str(), desc.as_str()).cmp(&(class_name, name, descriptor)))
+        .ok()
+        .map(|index| members[index].1.as_str())
+}
+
+impl Mapping {
+
+    ///
+    /// Compiles a translation table for a single direction between two namespaces.
+    ///
+    /// Supported namespaces are official, intermediary and named.
+    /// Missing and empty names both count as unmapped. Entries without a source name are left out,
+    /// entries without a target name keep their source name. Unlike `remap_class`, which returns
+    /// an empty official name as it is, an empty target name is never returned.
+    ///
+    pub fn compile(&self, from_namespace: &str, to_namespace: &str) -> Result<TranslationTable> {
+        let from = Namespace::parse(from_namespace)?;
+        let to = Namespace::parse(to_namespace)?;
+
+        // Member descriptors are stored in the official namespace and need to be translated into the source namespace.
+        let official_to_from: HashMap<&str, String> = self.classes.iter()
+            .filter_map(|(class_name, class_mapping)| {
+                let official_name = Namespace::Official.pick(class_name, &class_mapping.official_name, &class_mapping.intermediary_name)?;
+                Some((official_name, from.class_name(class_name, class_mapping)?))
+            })
+            .collect();
+        let from_descriptor = |descriptor: &str| {
+            let mut from_descriptor = String::with_capacity(descriptor.len());
+            map_descriptor(descriptor, &mut from_descriptor, |class_name| official_to_from.get(class_name).map(String::as_str));
+            from_descriptor
+        };
+
+        let mut classes = Vec::with_capacity(self.classes.len());
+        let mut methods = Vec::new();
+        let mut fields = Vec::new();
+
+        for (class_name, class_mapping) in &self.classes {
+            let Some(from_class_name) = from.class_name(class_name, class_mapping) else {
+                continue;
+            };
+            let to_class_name = to.class_name(class_name, class_mapping).unwrap_or_else(|| from_class_name.clone());
+
+            for ((method_name, descriptor), method_mapping) in &class_mapping.methods {
+                let Some(from_name) = from.pick(method_name, &method_mapping.official_name, &method_mapping.intermediary_name) else {
+                    continue;
+                };
+                let to_name = to.pick(method_name, &method_mapping.official_name, &method_mapping.intermediary_name).unwrap_or(from_name);
+                methods.push(((from_class_name.clone(), from_name.to_string(), from_descriptor(descriptor)), to_name.to_string()));
+            }
+
+            for ((field_name, descriptor), field_mapping) in &class_mapping.fields {
+                let Some(from_name) = from.pick(field_name, &field_mapping.official_name, &field_mapping.intermediary_name) else {
+                    continue;
+                };
+                let to_name = to.pick(field_name, &field_mapping.official_name, &field_mapping.intermediary_name).unwrap_or(from_name);
+                fields.push(((from_class_name.clone(), from_name.to_string(), from_descriptor(descriptor)), to_name.to_string()));
+            }
+
+            classes.push((from_class_name, to_class_name));
+        }
+
+        classes.sort_unstable();
+        methods.sort_unstable();
+        fields.sort_unstable();
+
+        Ok(TranslationTable {
+            from_namespace: from_namespace.to_string(),
+            to_namespace: to_namespace.to_string(),
+            classes,
+            methods,
+            fields,
+        })
+    }
+
+}